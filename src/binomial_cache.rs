@@ -1,16 +1,17 @@
 use lru::LruCache;
 use num_bigint::BigUint;
-use num_traits::One;
 use std::cmp::min;
 use std::num::NonZero;
 use std::sync::Mutex;
 
-pub struct BinomialCache {
-    cache: Mutex<LruCache<(u64, u64), BigUint>>,
-    precomputed: Vec<Vec<Option<BigUint>>>,
+use crate::index_int::IndexInt;
+
+pub struct BinomialCache<T: IndexInt> {
+    cache: Mutex<LruCache<(u64, u64), T>>,
+    precomputed: Vec<Vec<Option<T>>>,
 }
 
-impl BinomialCache {
+impl<T: IndexInt> BinomialCache<T> {
     const PRECOMPUTE_LIMIT: usize = 256;
 
     pub fn new() -> Self {
@@ -20,16 +21,16 @@ impl BinomialCache {
                 precomputed[n][k] = Some(compute_binomial(n as u64, k as u64));
             }
         }
-        
+
         BinomialCache {
             cache: Mutex::new(LruCache::new(NonZero::new(1024).unwrap())),
             precomputed,
         }
     }
 
-    pub fn get(&self, n: u64, k: u64) -> BigUint {
+    pub fn get(&self, n: u64, k: u64) -> T {
         let k = min(k, n - k);
-        
+
         if n < Self::PRECOMPUTE_LIMIT as u64 && k < Self::PRECOMPUTE_LIMIT as u64 {
             return self.precomputed[n as usize][k as usize].clone().unwrap();
         }
@@ -39,27 +40,75 @@ impl BinomialCache {
             return val.clone();
         }
 
-        let result = compute_binomial(n, k);
+        let result: T = compute_binomial(n, k);
         cache.put((n, k), result.clone());
         result
     }
 }
 
-fn compute_binomial(n: u64, k: u64) -> BigUint {
+fn compute_binomial<T: IndexInt>(n: u64, k: u64) -> T {
     if k == 0 || k == n {
-        return BigUint::one();
+        return T::one();
     }
-    
-    let mut result = BigUint::one();
+
+    let mut result = T::one();
     let k = min(k, n - k);
-    
+
     for i in 1..=k {
-        result = result * (n - k + i) / i;
+        result = result.mul_small(n - k + i).div_small(i);
     }
-    
+
     result
 }
 
 lazy_static::lazy_static! {
-    pub static ref BINOM_CACHE: BinomialCache = BinomialCache::new();
-}
\ No newline at end of file
+    pub static ref BINOM_CACHE: BinomialCache<BigUint> = BinomialCache::new();
+}
+
+/// Caches factorials so that [`FactorialCache::multinomial`] can evaluate
+/// `R! / (c_0! * c_1! * ... * c_{m-1}!)` — the count of distinct
+/// arrangements of a multiset with per-symbol counts `c_i` — without
+/// recomputing every factorial from scratch on each call.
+pub struct FactorialCache {
+    precomputed: Vec<BigUint>,
+}
+
+impl FactorialCache {
+    const PRECOMPUTE_LIMIT: u64 = 256;
+
+    pub fn new() -> Self {
+        let mut precomputed = Vec::with_capacity(Self::PRECOMPUTE_LIMIT as usize);
+        precomputed.push(BigUint::from(1u8));
+        for n in 1..Self::PRECOMPUTE_LIMIT {
+            let previous = precomputed.last().unwrap();
+            precomputed.push(previous * n);
+        }
+        FactorialCache { precomputed }
+    }
+
+    pub fn get(&self, n: u64) -> BigUint {
+        if n < self.precomputed.len() as u64 {
+            return self.precomputed[n as usize].clone();
+        }
+
+        let mut result = self.precomputed.last().unwrap().clone();
+        for i in self.precomputed.len() as u64..=n {
+            result *= i;
+        }
+        result
+    }
+
+    /// `R! / (c_0! * c_1! * ... * c_{m-1}!)` where `R = Σ c_i`.
+    pub fn multinomial(&self, counts: &[u64]) -> BigUint {
+        let total: u64 = counts.iter().sum();
+        let mut denominator = BigUint::from(1u8);
+        for &count in counts {
+            denominator *= self.get(count);
+        }
+        self.get(total) / denominator
+    }
+}
+
+lazy_static::lazy_static! {
+    pub static ref FACTORIAL_CACHE: FactorialCache = FactorialCache::new();
+}
@@ -0,0 +1,121 @@
+use bitvec::vec::BitVec;
+use num_bigint::BigUint;
+
+/// Magic bytes identifying a permutation_compression container, followed by
+/// a single version byte so the format can evolve without breaking readers
+/// of older containers.
+const MAGIC: [u8; 4] = *b"PMCC";
+const VERSION: u8 = 1;
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn read_varint(bytes: &[u8], pos: &mut usize) -> u64 {
+    let mut value = 0u64;
+    let mut shift = 0u32;
+    loop {
+        let byte = bytes[*pos];
+        *pos += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    value
+}
+
+/// Packs `bits` into a self-describing container: a magic/version header,
+/// the total bit length and the `ones` count as varints, and a
+/// varint-length-prefixed big-endian index. This is enough to `decompress`
+/// without the caller tracking `ones`/`zeros` out of band, so the result
+/// round-trips through a file or socket on its own.
+pub fn compress(bits: &BitVec) -> Vec<u8> {
+    let ones = bits.count_ones() as u64;
+    let total = bits.len() as u64;
+    let index = crate::stream_rank(bits.iter().map(|bit| *bit), ones, total - ones);
+    let index_bytes = index.to_bytes_be();
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&MAGIC);
+    out.push(VERSION);
+    write_varint(&mut out, total);
+    write_varint(&mut out, ones);
+    write_varint(&mut out, index_bytes.len() as u64);
+    out.extend_from_slice(&index_bytes);
+    out
+}
+
+/// Reverses [`compress`]: parses the header, reconstructs `ones`/`zeros`,
+/// and drives `stream_unrank` to regenerate the exact original bitstring.
+pub fn decompress(data: &[u8]) -> BitVec {
+    assert_eq!(
+        data.get(0..4),
+        Some(&MAGIC[..]),
+        "not a permutation_compression container"
+    );
+    assert_eq!(data[4], VERSION, "unsupported container version");
+
+    let mut pos = 5;
+    let total = read_varint(data, &mut pos);
+    let ones = read_varint(data, &mut pos);
+    let index_len = read_varint(data, &mut pos) as usize;
+    let index = BigUint::from_bytes_be(&data[pos..pos + index_len]);
+
+    crate::stream_unrank(index, ones, total - ones).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitvec::bitvec;
+    use bitvec::prelude::Lsb0;
+    use rand::{random_range, seq::SliceRandom};
+
+    fn random_bitvec(ones: usize, zeros: usize) -> BitVec {
+        let mut result = Vec::with_capacity(ones + zeros);
+        let ones = vec![true; ones];
+        let zeros = vec![false; zeros];
+        result.extend_from_slice(&ones);
+        result.extend_from_slice(&zeros);
+        let mut rng = rand::rng();
+        result.shuffle(&mut rng);
+        BitVec::from_iter(result)
+    }
+
+    #[test]
+    fn test_compress_decompress_roundtrip() {
+        let bits = bitvec![1, 0, 1, 1, 0];
+        let container = compress(&bits);
+        let result = decompress(&container);
+        assert_eq!(result, bits);
+    }
+
+    #[test]
+    fn test_roundtrip_random_input() {
+        let length: usize = 2000;
+        let ones = random_range(0usize..length);
+        let zeros = length - ones;
+        let expected = random_bitvec(ones, zeros);
+        let container = compress(&expected);
+        let result = decompress(&container);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "not a permutation_compression container")]
+    fn test_rejects_bad_magic() {
+        decompress(&[0, 0, 0, 0, 1, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,185 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::index_int::IndexInt;
+
+/// A fixed-size, stack-allocated unsigned integer backed by `N` 64-bit limbs
+/// in little-endian order (`0[0]` is least significant). Unlike `BigUint`,
+/// values of this type never allocate or reallocate, which matters in hot
+/// loops like `compute_binomial` and `BitRanker::process_chunk` where a
+/// heap-backed integer otherwise grows on every bit. Callers must choose `N`
+/// large enough that the index and every intermediate binomial coefficient
+/// fit in `N * 64` bits; arithmetic that overflows wraps silently modulo
+/// `2^(N * 64)`, in debug and release builds alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Uint<const N: usize>([u64; N]);
+
+impl<const N: usize> Uint<N> {
+    pub const ZERO: Self = Uint([0u64; N]);
+
+    pub fn from_u64(value: u64) -> Self {
+        let mut limbs = [0u64; N];
+        if N > 0 {
+            limbs[0] = value;
+        }
+        Uint(limbs)
+    }
+
+    pub fn limbs(&self) -> &[u64; N] {
+        &self.0
+    }
+
+    fn add_with_carry(&self, other: &Self) -> Self {
+        let mut result = [0u64; N];
+        let mut carry = 0u128;
+        for ((r, &a), &b) in result.iter_mut().zip(&self.0).zip(&other.0) {
+            let sum = a as u128 + b as u128 + carry;
+            *r = sum as u64;
+            carry = sum >> 64;
+        }
+        Uint(result)
+    }
+
+    fn sub_with_borrow(&self, other: &Self) -> Self {
+        let mut result = [0u64; N];
+        let mut borrow = 0i128;
+        for ((r, &a), &b) in result.iter_mut().zip(&self.0).zip(&other.0) {
+            let diff = a as i128 - b as i128 - borrow;
+            if diff < 0 {
+                *r = (diff + (1i128 << 64)) as u64;
+                borrow = 1;
+            } else {
+                *r = diff as u64;
+                borrow = 0;
+            }
+        }
+        Uint(result)
+    }
+
+    /// Schoolbook multiply by a single 64-bit scalar; any carry out of the
+    /// `N`th limb is truncated.
+    fn mul_u64(&self, scalar: u64) -> Self {
+        let mut result = [0u64; N];
+        let mut carry = 0u128;
+        for (r, &a) in result.iter_mut().zip(&self.0) {
+            let product = a as u128 * scalar as u128 + carry;
+            *r = product as u64;
+            carry = product >> 64;
+        }
+        Uint(result)
+    }
+
+    /// Single-limb division: long division by a 64-bit scalar, most
+    /// significant limb first.
+    fn div_u64(&self, divisor: u64) -> Self {
+        let mut result = [0u64; N];
+        let mut remainder = 0u128;
+        for (r, &a) in result.iter_mut().zip(&self.0).rev() {
+            let dividend = (remainder << 64) | a as u128;
+            *r = (dividend / divisor as u128) as u64;
+            remainder = dividend % divisor as u128;
+        }
+        Uint(result)
+    }
+}
+
+impl<const N: usize> PartialOrd for Uint<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Uint<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in (0..N).rev() {
+            match self.0[i].cmp(&other.0[i]) {
+                Ordering::Equal => continue,
+                ord => return ord,
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl<const N: usize> Add for Uint<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add_with_carry(&rhs)
+    }
+}
+
+impl<const N: usize> AddAssign<&Uint<N>> for Uint<N> {
+    fn add_assign(&mut self, rhs: &Uint<N>) {
+        *self = self.add_with_carry(rhs);
+    }
+}
+
+impl<const N: usize> Sub for Uint<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_with_borrow(&rhs)
+    }
+}
+
+impl<const N: usize> SubAssign<&Uint<N>> for Uint<N> {
+    fn sub_assign(&mut self, rhs: &Uint<N>) {
+        *self = self.sub_with_borrow(rhs);
+    }
+}
+
+impl<const N: usize> IndexInt for Uint<N> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::from_u64(1)
+    }
+
+    fn add_ref(&mut self, other: &Self) {
+        *self += other;
+    }
+
+    fn sub_ref(&mut self, other: &Self) {
+        *self -= other;
+    }
+
+    fn mul_small(&self, scalar: u64) -> Self {
+        self.mul_u64(scalar)
+    }
+
+    fn div_small(&self, divisor: u64) -> Self {
+        self.div_u64(divisor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_sub_roundtrip() {
+        let a = Uint::<4>::from_u64(12345);
+        let b = Uint::<4>::from_u64(6789);
+        let sum = a + b;
+        assert_eq!(sum - b, a);
+    }
+
+    #[test]
+    fn test_mul_div_roundtrip() {
+        let a = Uint::<4>::from_u64(999);
+        let scaled = a.mul_u64(7);
+        assert_eq!(scaled.div_u64(7), a);
+    }
+
+    #[test]
+    fn test_ordering_across_limbs() {
+        let small = Uint::<2>::from_u64(1);
+        let mut large = [0u64; 2];
+        large[1] = 1;
+        let large = Uint(large);
+        assert!(small < large);
+    }
+}
@@ -0,0 +1,44 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// Common interface for the integer types used as enumerative-coding
+/// indices: the arbitrary-precision, heap-backed [`BigUint`] and the
+/// fixed-size, stack-allocated [`crate::fixed_uint::Uint`]. Letting
+/// [`crate::streaming::BitRanker`]/[`crate::streaming::BitUnranker`] and
+/// [`crate::binomial_cache::BinomialCache`] be generic over this trait means
+/// the same ranking/unranking code serves both an unbounded `BigUint` path
+/// and an allocation-free fixed-width path.
+pub trait IndexInt: Clone + PartialOrd {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add_ref(&mut self, other: &Self);
+    fn sub_ref(&mut self, other: &Self);
+    fn mul_small(&self, scalar: u64) -> Self;
+    fn div_small(&self, divisor: u64) -> Self;
+}
+
+impl IndexInt for BigUint {
+    fn zero() -> Self {
+        Zero::zero()
+    }
+
+    fn one() -> Self {
+        One::one()
+    }
+
+    fn add_ref(&mut self, other: &Self) {
+        *self += other;
+    }
+
+    fn sub_ref(&mut self, other: &Self) {
+        *self -= other;
+    }
+
+    fn mul_small(&self, scalar: u64) -> Self {
+        self.clone() * scalar
+    }
+
+    fn div_small(&self, divisor: u64) -> Self {
+        self.clone() / divisor
+    }
+}
@@ -2,6 +2,11 @@ use num_bigint::BigUint;
 use streaming::{BitRanker, BitUnranker};
 
 pub mod binomial_cache;
+pub mod codec;
+pub mod fixed_uint;
+pub mod index_int;
+pub mod multiset;
+pub mod secded;
 pub mod streaming;
 use bitvec::{bitvec, order::Lsb0};
 
@@ -78,6 +83,57 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn test_rank_parallel_matches_stream_rank() {
+        let length: usize = 1000;
+        let ones = random_range(0usize..length);
+        let zeros = length - ones;
+        let expected = random_bitvec(ones, zeros);
+        let bits: BitVec<u64, Lsb0> = expected.iter().map(|bit| *bit).collect();
+        let sequential = stream_rank(expected.iter().map(|bit| *bit), ones as u64, zeros as u64);
+        let parallel = streaming::rank_parallel(&bits, ones as u64, zeros as u64);
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn test_stream_unrank_sparse_long_zero_runs() {
+        // Exercises BitUnranker's run-skipping fast path, which only
+        // triggers once several zeros remain in a row.
+        let length: usize = 5000;
+        let ones = random_range(0usize..50);
+        let zeros = length - ones;
+        let expected = random_bitvec(ones, zeros);
+        let index = stream_rank(expected.iter().map(|bit| *bit), ones as u64, zeros as u64);
+        let result = stream_unrank(index, ones as u64, zeros as u64).collect::<BitVec>();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_fixed_uint_backend_matches_biguint_backend() {
+        use binomial_cache::BinomialCache;
+        use fixed_uint::Uint;
+
+        let length: usize = 500;
+        let ones = random_range(0usize..length);
+        let zeros = length - ones;
+        let expected = random_bitvec(ones, zeros);
+        let bits: BitVec<u64, Lsb0> = expected.iter().map(|bit| *bit).collect();
+
+        let cache: BinomialCache<Uint<16>> = BinomialCache::new();
+        let mut ranker = BitRanker::with_cache(ones as u64, zeros as u64, &cache);
+        ranker.process_chunk(&bits);
+        let index = ranker.finalize();
+
+        let biguint_index = stream_rank(expected.iter().map(|bit| *bit), ones as u64, zeros as u64);
+        let mut biguint_limbs = biguint_index.to_u64_digits();
+        biguint_limbs.resize(16, 0);
+        assert_eq!(&biguint_limbs[..], &index.limbs()[..]);
+
+        let mut unranker = BitUnranker::with_cache(index, ones as u64, zeros as u64, &cache);
+        let result = unranker.next_chunk(length);
+        assert_eq!(result, bits);
+    }
+
     #[test]
     fn test_cost() {
         let length: usize = 1000;
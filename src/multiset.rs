@@ -0,0 +1,134 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::binomial_cache::FACTORIAL_CACHE;
+
+/// Ranks a sequence over an `m`-symbol alphabet with a known multiset of
+/// per-symbol counts, generalizing [`crate::streaming::BitRanker`] (which is
+/// the `m = 2` special case) to arbitrary alphabets.
+///
+/// At each position, placing symbol `s` contributes the number of
+/// arrangements that would have come before it: the sum, over every smaller
+/// symbol `t` that still has remaining count, of the arrangements of the
+/// remaining multiset with one fewer `t`.
+pub struct SymbolRanker {
+    counts: Vec<u64>,
+    current_index: BigUint,
+}
+
+impl SymbolRanker {
+    pub fn new(counts: Vec<u64>) -> Self {
+        SymbolRanker {
+            counts,
+            current_index: BigUint::zero(),
+        }
+    }
+
+    pub fn process_symbol(&mut self, symbol: usize) {
+        for t in 0..symbol {
+            if self.counts[t] == 0 {
+                continue;
+            }
+            let mut reduced = self.counts.clone();
+            reduced[t] -= 1;
+            self.current_index += FACTORIAL_CACHE.multinomial(&reduced);
+        }
+        self.counts[symbol] -= 1;
+    }
+
+    pub fn finalize(self) -> BigUint {
+        self.current_index
+    }
+}
+
+/// Reverses [`SymbolRanker`]: at each position, walks symbols in order and
+/// subtracts their block of the index until the remaining index falls
+/// inside one symbol's block, which is then emitted.
+pub struct SymbolUnranker {
+    remaining_index: BigUint,
+    counts: Vec<u64>,
+}
+
+impl SymbolUnranker {
+    pub fn new(index: BigUint, counts: Vec<u64>) -> Self {
+        SymbolUnranker {
+            remaining_index: index,
+            counts,
+        }
+    }
+
+    pub fn next_symbol(&mut self) -> Option<usize> {
+        if self.counts.iter().all(|&count| count == 0) {
+            return None;
+        }
+
+        for symbol in 0..self.counts.len() {
+            if self.counts[symbol] == 0 {
+                continue;
+            }
+            let mut reduced = self.counts.clone();
+            reduced[symbol] -= 1;
+            let block = FACTORIAL_CACHE.multinomial(&reduced);
+
+            if self.remaining_index < block {
+                self.counts[symbol] -= 1;
+                return Some(symbol);
+            }
+            self.remaining_index -= &block;
+        }
+
+        unreachable!("remaining index exceeds the total permutation count")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::seq::SliceRandom;
+
+    fn random_symbols(counts: &[u64]) -> Vec<usize> {
+        let mut symbols = Vec::new();
+        for (symbol, &count) in counts.iter().enumerate() {
+            symbols.extend(std::iter::repeat_n(symbol, count as usize));
+        }
+        let mut rng = rand::rng();
+        symbols.shuffle(&mut rng);
+        symbols
+    }
+
+    #[test]
+    fn test_rank_unrank_roundtrip() {
+        let counts = vec![4u64, 3, 5, 2];
+        let expected = random_symbols(&counts);
+
+        let mut ranker = SymbolRanker::new(counts.clone());
+        for &symbol in &expected {
+            ranker.process_symbol(symbol);
+        }
+        let index = ranker.finalize();
+
+        let mut unranker = SymbolUnranker::new(index, counts);
+        let mut result = Vec::new();
+        while let Some(symbol) = unranker.next_symbol() {
+            result.push(symbol);
+        }
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_binary_alphabet_matches_bit_ranking() {
+        // With a 2-symbol alphabet, SymbolRanker should agree with the
+        // binomial-coefficient math BitRanker uses for bits.
+        let counts = vec![2u64, 3];
+        let symbols = vec![1, 0, 1, 1, 0];
+
+        let mut ranker = SymbolRanker::new(counts);
+        for &symbol in &symbols {
+            ranker.process_symbol(symbol);
+        }
+        let index = ranker.finalize();
+
+        assert_eq!(index, BigUint::from(6u8));
+    }
+}
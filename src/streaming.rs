@@ -4,25 +4,32 @@ use bitvec::{order::Lsb0, vec::BitVec};
 use num_bigint::BigUint;
 use num_traits::Zero;
 use bitvec::bitvec;
+use rayon::prelude::*;
 
-use crate::binomial_cache::BINOM_CACHE;
+use crate::binomial_cache::{BinomialCache, BINOM_CACHE};
+use crate::index_int::IndexInt;
 
-pub struct BitRanker {
+pub struct BitRanker<'a, T: IndexInt = BigUint> {
     total_ones: u64,
     total_zeros: u64,
     remaining_ones: u64,
     remaining_zeros: u64,
-    current_index: BigUint,
+    current_index: T,
+    cache: &'a BinomialCache<T>,
 }
 
-impl BitRanker {
-    pub fn new(total_ones: u64, total_zeros: u64) -> Self {
+impl<'a, T: IndexInt> BitRanker<'a, T> {
+    /// Builds a ranker backed by a caller-supplied binomial cache, letting
+    /// this be used with any `IndexInt` (e.g. a fixed-size `Uint<N>`) and not
+    /// just the globally-cached `BigUint` path.
+    pub fn with_cache(total_ones: u64, total_zeros: u64, cache: &'a BinomialCache<T>) -> Self {
         BitRanker {
             total_ones,
             total_zeros,
             remaining_ones: total_ones,
             remaining_zeros: total_zeros,
-            current_index: BigUint::zero(),
+            current_index: T::zero(),
+            cache,
         }
     }
 
@@ -33,10 +40,10 @@ impl BitRanker {
             }
 
             if *bit {
-                let n = (self.remaining_ones + self.remaining_zeros - 1) as u64;
-                let k = self.remaining_ones as u64;
-                let c = BINOM_CACHE.get(n, k);
-                self.current_index += c;
+                let n = self.remaining_ones + self.remaining_zeros - 1;
+                let k = self.remaining_ones;
+                let c = self.cache.get(n, k);
+                self.current_index.add_ref(&c);
                 self.remaining_ones -= 1;
             } else {
                 self.remaining_zeros -= 1;
@@ -44,57 +51,153 @@ impl BitRanker {
         }
     }
 
-    pub fn finalize(self) -> BigUint {
+    pub fn finalize(self) -> T {
         self.current_index
     }
 }
 
-pub struct BitUnranker {
-    remaining_index: BigUint,
+impl<'a> BitRanker<'a, BigUint> {
+    pub fn new(total_ones: u64, total_zeros: u64) -> Self {
+        Self::with_cache(total_ones, total_zeros, &BINOM_CACHE)
+    }
+}
+
+/// Ranks `bits` the same way [`BitRanker`] does, but computes every set bit's
+/// contribution independently from suffix one-counts and sums them with a
+/// `rayon` parallel reduce instead of a sequential running total. Ranking is
+/// a commutative sum, so this produces the exact same index as
+/// [`BitRanker::process_chunk`] while scaling across cores on large inputs.
+pub fn rank_parallel(bits: &BitVec<u64, Lsb0>, ones: u64, zeros: u64) -> BigUint {
+    let len = bits.len();
+    debug_assert_eq!(len as u64, ones + zeros);
+
+    let mut suffix_ones = vec![0u64; len + 1];
+    for i in (0..len).rev() {
+        suffix_ones[i] = suffix_ones[i + 1] + bits[i] as u64;
+    }
+
+    (0..len)
+        .into_par_iter()
+        .filter(|&i| bits[i])
+        .map(|i| {
+            let remaining_ones = suffix_ones[i];
+            let remaining_total = (len - i) as u64;
+            let remaining_zeros = remaining_total - remaining_ones;
+            if remaining_zeros == 0 {
+                // No zeros left in the suffix: every remaining position is
+                // forced to be a one, so this and every later one in the
+                // same trailing run contributes nothing to the index.
+                <BigUint as Zero>::zero()
+            } else {
+                BINOM_CACHE.get(remaining_ones + remaining_zeros - 1, remaining_ones)
+            }
+        })
+        .reduce(<BigUint as Zero>::zero, |a, b| a + b)
+}
+
+pub struct BitUnranker<'a, T: IndexInt = BigUint> {
+    remaining_index: T,
     remaining_ones: u64,
     remaining_zeros: u64,
+    cache: &'a BinomialCache<T>,
 }
 
-impl BitUnranker {
-    pub fn new(index: BigUint, total_ones: u64, total_zeros: u64) -> Self {
+impl<'a, T: IndexInt> BitUnranker<'a, T> {
+    /// Builds an unranker backed by a caller-supplied binomial cache; see
+    /// [`BitRanker::with_cache`].
+    pub fn with_cache(index: T, total_ones: u64, total_zeros: u64, cache: &'a BinomialCache<T>) -> Self {
         BitUnranker {
             remaining_index: index,
             remaining_ones: total_ones,
             remaining_zeros: total_zeros,
+            cache,
         }
     }
 
     pub fn next_chunk(&mut self, chunk_size: usize) -> BitVec<u64, Lsb0> {
         let chunk_size = min(chunk_size as u64, self.remaining_ones + self.remaining_zeros) as usize;
         let mut result = bitvec![u64, Lsb0; 0; chunk_size];
-        
-        for i in 0..chunk_size {
+
+        let mut i = 0;
+        while i < chunk_size {
             if self.remaining_ones == 0 {
                 result.set(i, false);
                 self.remaining_zeros = self.remaining_zeros.saturating_sub(1);
+                i += 1;
                 continue;
             }
-            
+
             if self.remaining_zeros == 0 {
                 result.set(i, true);
                 self.remaining_ones -= 1;
+                i += 1;
                 continue;
             }
 
-            let n = (self.remaining_ones + self.remaining_zeros - 1) as u64;
-            let k = self.remaining_ones as u64;
-            let c = BINOM_CACHE.get(n, k);
-            
+            if self.remaining_zeros >= Self::RUN_SKIP_MIN_ZEROS {
+                let run = min(self.skippable_zero_run(), (chunk_size - i) as u64) as usize;
+                if run > 1 {
+                    // `result` is zero-initialized, so a run of leading
+                    // zeros needs no writes here, only bookkeeping.
+                    self.remaining_zeros -= run as u64;
+                    i += run;
+                    continue;
+                }
+            }
+
+            let n = self.remaining_ones + self.remaining_zeros - 1;
+            let k = self.remaining_ones;
+            let c = self.cache.get(n, k);
+
             if c > self.remaining_index {
                 result.set(i, false);
                 self.remaining_zeros -= 1;
             } else {
                 result.set(i, true);
-                self.remaining_index -= &c;
+                self.remaining_index.sub_ref(&c);
                 self.remaining_ones -= 1;
             }
+            i += 1;
         }
-        
+
         result
     }
-}
\ No newline at end of file
+
+    /// Below this many remaining zeros, a binary search costs more
+    /// binomial lookups than it saves over the bit-by-bit path.
+    const RUN_SKIP_MIN_ZEROS: u64 = 4;
+
+    /// Binary-searches `1..=remaining_zeros` for the largest value at which
+    /// `C(remaining_ones + remaining_zeros - 1, remaining_ones)` is at or
+    /// below `remaining_index`, i.e. the first point, counting down, where a
+    /// one is placed. That binomial shrinks monotonically as
+    /// `remaining_zeros` shrinks, so the whole run of leading zeros up to
+    /// that point can be emitted in bulk instead of recomputing a binomial
+    /// for every one of them. Returns the length of that run.
+    fn skippable_zero_run(&self) -> u64 {
+        let mut lo = 1u64;
+        let mut hi = self.remaining_zeros;
+        let mut below_threshold: Option<u64> = None;
+
+        while lo <= hi {
+            let mid = lo + (hi - lo) / 2;
+            let n = self.remaining_ones + mid - 1;
+            let c = self.cache.get(n, self.remaining_ones);
+
+            if c <= self.remaining_index {
+                below_threshold = Some(mid);
+                lo = mid + 1;
+            } else {
+                hi = mid - 1;
+            }
+        }
+
+        self.remaining_zeros - below_threshold.unwrap_or(0)
+    }
+}
+
+impl<'a> BitUnranker<'a, BigUint> {
+    pub fn new(index: BigUint, total_ones: u64, total_zeros: u64) -> Self {
+        Self::with_cache(index, total_ones, total_zeros, &BINOM_CACHE)
+    }
+}